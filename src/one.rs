@@ -1,6 +1,14 @@
 //! Provider of [`One`].
 
+use core::borrow::{Borrow, BorrowMut};
+use core::cell::UnsafeCell;
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{Hash, Hasher};
 use core::ops::{Deref, DerefMut};
+#[cfg(debug_assertions)]
+use core::panic::Location;
+use core::sync::atomic::{AtomicBool, Ordering as AtomicOrdering};
 
 /// Wrapper to handle value consumption.
 ///
@@ -8,6 +16,43 @@ use core::ops::{Deref, DerefMut};
 /// It can consume its value only once via [`take`](Self::take) method.
 /// After calling `take`, all dereferences cause panic.
 ///
+/// Its value can also be constructed lazily via [`new_lazy`](Self::new_lazy).
+/// In that case, the given evaluator runs only once, on first access, and
+/// the produced value is cached for every access after that.
+///
+/// Additionally, [`with_drop`](Self::with_drop) attaches a closure that
+/// receives the value on drop, if it was never taken. This spares callers
+/// from writing their own [`Drop`] impl just to forward an unconsumed value
+/// somewhere else.
+///
+/// Like other smart pointers such as [`Box`], it also implements
+/// [`AsRef`], [`AsMut`], [`Borrow`] and [`BorrowMut`] for its value, and
+/// offers [`as_deref`](Self::as_deref)/[`as_deref_mut`](Self::as_deref_mut)
+/// for viewing through an extra layer of [`Deref`].
+///
+/// For cases where a panic is not wanted, [`get`](Self::get),
+/// [`get_mut`](Self::get_mut) and [`take_opt`](Self::take_opt) mirror their
+/// [`Option`] counterparts, [`into_inner`](Self::into_inner) consumes the
+/// wrapper entirely to the same effect, and [`replace`](Self::replace),
+/// [`insert`](Self::insert) and
+/// [`get_or_insert_with`](Self::get_or_insert_with) let a value be put back
+/// once it has been taken.
+///
+/// In debug builds, a successful [`take`](Self::take) records its call site,
+/// so the panic raised by a later access names the offending `take` instead
+/// of just the access itself.
+///
+/// `One<T>` is [`Send`] whenever `T` is [`Send`], and [`Sync`] whenever `T`
+/// is both [`Send`] and [`Sync`] (lazy and
+/// on-drop closures must themselves be `Send`, see [`new_lazy`](Self::new_lazy)
+/// and [`with_drop`](Self::with_drop)), and manually implements [`Debug`],
+/// [`Clone`], [`PartialEq`], [`Eq`], [`PartialOrd`], [`Ord`] and [`Hash`] for
+/// `T`'s own impls of those, since `#[derive]` cannot see through its
+/// internal state. It cannot implement [`Copy`], since [`Drop`] (needed for
+/// [`with_drop`](Self::with_drop)) rules that out.
+///
+/// [`Debug`]: core::fmt::Debug
+///
 /// Internally, This type is super simple newtype of [`Option`].
 /// However, it sometimes makes code simpler than using `Option` directly.
 /// (Especially, types that implement [`Drop`] are a good example of this.)
@@ -60,48 +105,482 @@ use core::ops::{Deref, DerefMut};
 ///     }
 /// }
 /// ```
-#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
-pub struct One<T>(Option<T>);
+pub struct One<T> {
+    state: UnsafeCell<State<T>>,
+    lock: AtomicBool,
+    on_drop: Option<Box<dyn FnMut(T) + Send>>,
+    taken_at: TakenAt,
+}
+
+// SAFETY: `lock` serializes every *mutation* of `state` made through
+// `&self` (see `with_locked_state`), but `get`/`deref`/`as_ref`/`borrow`
+// all hand out a plain `&T` that outlives the lock, so distinct threads
+// can end up holding `&T` to the same value concurrently with no
+// synchronization between them. That requires `T: Sync`, the same bound
+// `RwLock<T>` uses for its own `Sync` impl (for the same reason: it also
+// exposes concurrent shared readers, unlike `Mutex<T>`, which never hands
+// out a bare `&T` and so only needs `T: Send`).
+unsafe impl<T: Send + Sync> Sync for One<T> {}
+
+/// Internal state of [`One`].
+enum State<T> {
+    /// Value not constructed yet. Holds the evaluator until it runs.
+    Uninit(Option<Box<dyn FnOnce() -> T + Send>>),
+
+    /// Value is present.
+    Ready(T),
+
+    /// Value was taken out (or never provided).
+    Taken,
+}
+
+impl<T> State<T> {
+    /// Returns a reference to the value, if this is [`Ready`](Self::Ready).
+    fn as_ready(&self) -> Option<&T> {
+        match self {
+            Self::Ready(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns a mutable reference to the value, if this is [`Ready`](Self::Ready).
+    fn as_ready_mut(&mut self) -> Option<&mut T> {
+        match self {
+            Self::Ready(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns the value, if this is [`Ready`](Self::Ready).
+    fn into_ready(self) -> Option<T> {
+        match self {
+            Self::Ready(value) => Some(value),
+            _ => None,
+        }
+    }
+}
+
+/// Call site of the `take` that emptied a [`One`], if any.
+///
+/// This is only tracked in debug builds, so a release build's [`One`] stays
+/// as small as its state and on-drop closure alone require.
+#[cfg(debug_assertions)]
+type TakenAt = Option<&'static Location<'static>>;
+
+/// Call site of the `take` that emptied a [`One`], if any.
+///
+/// This is only tracked in debug builds, so a release build's [`One`] stays
+/// as small as its state and on-drop closure alone require.
+#[cfg(not(debug_assertions))]
+type TakenAt = ();
+
+#[cfg(debug_assertions)]
+fn no_taken_at() -> TakenAt {
+    None
+}
+
+#[cfg(not(debug_assertions))]
+fn no_taken_at() -> TakenAt {}
+
+#[cfg(debug_assertions)]
+#[track_caller]
+fn here_taken_at() -> TakenAt {
+    Some(Location::caller())
+}
+
+#[cfg(not(debug_assertions))]
+fn here_taken_at() -> TakenAt {}
 
 impl<T> One<T> {
     /// Creates an instance.
     pub fn new(value: T) -> Self {
-        Self(Some(value))
+        Self {
+            state: UnsafeCell::new(State::Ready(value)),
+            lock: AtomicBool::new(false),
+            on_drop: None,
+            taken_at: no_taken_at(),
+        }
     }
 
     /// Creates an empty instance.
     pub fn none() -> Self {
-        Self(None)
+        Self {
+            state: UnsafeCell::new(State::Taken),
+            lock: AtomicBool::new(false),
+            on_drop: None,
+            taken_at: no_taken_at(),
+        }
+    }
+
+    /// Creates an instance whose value is constructed lazily.
+    ///
+    /// `f` is not called here. It runs at most once, the first time the
+    /// value is accessed (by [`deref`](Self::deref), [`deref_mut`](Self::deref_mut)
+    /// or [`take`](Self::take)), and its result is cached for later accesses.
+    ///
+    /// `f` must be [`Send`] so that a `One<T>` built from it stays [`Send`]
+    /// (and, together with the locking `force` does internally, [`Sync`])
+    /// regardless of whether it has been evaluated yet.
+    pub fn new_lazy(f: impl FnOnce() -> T + Send + 'static) -> Self {
+        Self {
+            state: UnsafeCell::new(State::Uninit(Some(Box::new(f)))),
+            lock: AtomicBool::new(false),
+            on_drop: None,
+            taken_at: no_taken_at(),
+        }
+    }
+
+    /// Creates an instance with a closure to run on drop.
+    ///
+    /// If this wrapper is dropped while its value is still present, `on_drop`
+    /// is invoked with the owned value. If the value was already [`take`]n
+    /// (or disarmed via [`drop_by`]), `on_drop` is skipped.
+    ///
+    /// `on_drop` must be [`Send`] for the same reason [`new_lazy`](Self::new_lazy)'s
+    /// evaluator must be.
+    ///
+    /// [`take`]: Self::take
+    /// [`drop_by`]: Self::drop_by
+    pub fn with_drop(value: T, on_drop: impl FnMut(T) + Send + 'static) -> Self {
+        Self {
+            state: UnsafeCell::new(State::Ready(value)),
+            lock: AtomicBool::new(false),
+            on_drop: Some(Box::new(on_drop)),
+            taken_at: no_taken_at(),
+        }
     }
 
-    /// Returns `true` if value exists.
+    /// Returns `true` if value exists, or is still lazily obtainable.
     pub fn exists(this: &Self) -> bool {
-        this.0.is_some()
+        this.with_locked_state(|state| !matches!(state, State::Taken))
     }
 
     /// Takes the value out of this wrapper.
     ///
+    /// If the value is still uninitialized, this forces its construction first.
+    /// This also disarms any closure set via [`with_drop`](Self::with_drop),
+    /// since the value is no longer this wrapper's to hand over.
+    ///
+    /// In debug builds, this call site is recorded, and named in the panic
+    /// message if this `One` is accessed again afterward.
+    ///
     /// # Panics
     ///
     /// Panics if this value taken already.
+    #[track_caller]
     pub fn take(this: &mut Self) -> T {
-        Self::expect(this.0.take())
+        this.force();
+        let prior = core::mem::replace(this.state.get_mut(), State::Taken);
+        let value = match prior.into_ready() {
+            Some(value) => value,
+            None => this.panic_taken(),
+        };
+
+        this.on_drop = None;
+        this.taken_at = here_taken_at();
+        value
+    }
+
+    /// Fires the closure set via [`with_drop`](Self::with_drop) early, with
+    /// the value, and leaves this wrapper empty so it can be reused.
+    ///
+    /// Does nothing if the value was already taken. A value that is still
+    /// lazily obtainable is dropped without being constructed, the same way
+    /// it would be if it had never been accessed, so `on_drop` never sees a
+    /// value that did not already exist.
+    pub fn drop_by(this: &mut Self) {
+        let value = core::mem::replace(this.state.get_mut(), State::Taken).into_ready();
+        if let (Some(value), Some(on_drop)) = (value, &mut this.on_drop) {
+            on_drop(value);
+        }
+
+        this.on_drop = None;
+    }
+
+    /// Returns a reference to the value, or `None` if it was already taken.
+    ///
+    /// Unlike [`deref`](Self::deref), this returns `None` instead of
+    /// panicking when the value was already taken. A still-uninitialized
+    /// value is forced first, which panics if its evaluator panics or was
+    /// already consumed by another call.
+    pub fn get(this: &Self) -> Option<&T> {
+        this.force();
+        let ptr = this.with_locked_state(|state| state.as_ready().map(|value| value as *const T));
+
+        // SAFETY: once `state` holds `Ready`, only `&mut Self`-taking methods
+        // can change it again, and those cannot run while this `&Self`
+        // borrow (which the returned reference's lifetime is tied to) is
+        // alive. So the pointer captured above stays valid for as long as
+        // the reference we hand back is used.
+        ptr.map(|ptr| unsafe { &*ptr })
     }
 
-    /// Returns some value of argument.
+    /// Returns a mutable reference to the value, or `None` if it was already
+    /// taken.
+    ///
+    /// Unlike [`deref_mut`](Self::deref_mut), this returns `None` instead of
+    /// panicking when the value was already taken. A still-uninitialized
+    /// value is forced first, which panics if its evaluator panics or was
+    /// already consumed by another call.
+    pub fn get_mut(this: &mut Self) -> Option<&mut T> {
+        this.force();
+        this.state.get_mut().as_ready_mut()
+    }
+
+    /// Takes the value out of this wrapper, or returns `None` if it was
+    /// already taken.
+    ///
+    /// Unlike [`take`](Self::take), this returns `None` instead of panicking
+    /// when the value was already taken, and it does not record a call site
+    /// for later panic messages. A still-uninitialized value is forced first,
+    /// which panics if its evaluator panics or was already consumed.
+    pub fn take_opt(this: &mut Self) -> Option<T> {
+        this.force();
+        let value = core::mem::replace(this.state.get_mut(), State::Taken).into_ready();
+        if value.is_some() {
+            this.on_drop = None;
+        }
+
+        value
+    }
+
+    /// Consumes this wrapper, extracting the value if present, and disarms
+    /// any closure set via [`with_drop`](Self::with_drop) so it does not
+    /// also run.
+    ///
+    /// This is the consuming counterpart of [`take_opt`](Self::take_opt):
+    /// `into_inner(this)` is equivalent to calling `take_opt(&mut this)` and
+    /// then dropping the now-empty `this`.
+    pub fn into_inner(mut this: Self) -> Option<T> {
+        Self::take_opt(&mut this)
+    }
+
+    /// Puts `value` into this wrapper, returning the previous value if any.
+    ///
+    /// This re-arms a wrapper whose value was already taken. If the previous
+    /// value was still lazily obtainable, it is discarded unconstructed, so
+    /// `None` is returned for it rather than paying its construction cost
+    /// just to immediately replace it.
+    pub fn replace(this: &mut Self, value: T) -> Option<T> {
+        core::mem::replace(this.state.get_mut(), State::Ready(value)).into_ready()
+    }
+
+    /// Puts `value` into this wrapper, overwriting any previous value, and
+    /// returns a mutable reference to it.
+    pub fn insert(this: &mut Self, value: T) -> &mut T {
+        *this.state.get_mut() = State::Ready(value);
+        this.state.get_mut().as_ready_mut().expect("just inserted")
+    }
+
+    /// Returns a mutable reference to the value, constructing it with `f`
+    /// first if it was still uninitialized or already taken.
+    ///
+    /// If the value was still lazily obtainable via [`new_lazy`](Self::new_lazy),
+    /// that evaluator is discarded unconstructed in favor of `f`, rather than
+    /// run just to be immediately overwritten.
+    pub fn get_or_insert_with(this: &mut Self, f: impl FnOnce() -> T) -> &mut T {
+        let state = this.state.get_mut();
+        if state.as_ready().is_none() {
+            *state = State::Ready(f());
+        }
+
+        this.state.get_mut().as_ready_mut().expect("just inserted")
+    }
+
+    /// Forces construction of the value, if it is still uninitialized.
+    fn force(&self) {
+        // `None` means `state` was not `Uninit` (nothing to do); `Some(None)`
+        // means it was `Uninit`, but the evaluator had already been taken out
+        // by another call; `Some(Some(f))` hands back the evaluator to run.
+        let taken = self.with_locked_state(|state| match state {
+            State::Uninit(f) => Some(f.take()),
+            _ => None,
+        });
+
+        // The lock is released before the evaluator runs below (it is no
+        // longer reachable from `state`), so a reentrant call made from
+        // within the evaluator (or a genuinely concurrent one on another
+        // thread) only ever observes `Uninit(None)` and panics, instead of
+        // either recursing with a live `&mut State<T>` still on the stack,
+        // or deadlocking on a lock this same thread already holds.
+        let Some(evaluator) = taken else {
+            return;
+        };
+        let f = evaluator.expect("Evaluator already consumed.");
+        let value = f();
+        self.with_locked_state(|state| *state = State::Ready(value));
+    }
+
+    /// Runs `f` with exclusive access to `state`, spinning until any other
+    /// call (on this or another thread) currently in its own critical
+    /// section finishes.
+    ///
+    /// This is the only way `state` is ever touched through `&self`; every
+    /// `&mut self` method bypasses it in favor of `state.get_mut()`, which is
+    /// already exclusive by the borrow checker's own rules.
+    ///
+    /// `f` must not itself touch `self` (directly, or by calling back into
+    /// `One`'s own methods), and should do as little work as possible —
+    /// notably, it must not run caller-supplied code such as `T`'s own
+    /// `Debug`/`Clone`/`PartialEq` impls, since that would hold the lock for
+    /// the duration of arbitrary user code. Pull out a raw pointer instead,
+    /// and dereference it after this call returns (see `get`/`deref`).
+    fn with_locked_state<R>(&self, f: impl FnOnce(&mut State<T>) -> R) -> R {
+        while self
+            .lock
+            .compare_exchange_weak(false, true, AtomicOrdering::Acquire, AtomicOrdering::Relaxed)
+            .is_err()
+        {
+            core::hint::spin_loop();
+        }
+
+        // Releases the lock on the way out, including on unwind, so a panic
+        // inside `f` (or inside caller-supplied code `f` wraps) can never
+        // leave `self` permanently locked for every later access.
+        struct Unlock<'a>(&'a AtomicBool);
+        impl Drop for Unlock<'_> {
+            fn drop(&mut self) {
+                self.0.store(false, AtomicOrdering::Release);
+            }
+        }
+        let _unlock = Unlock(&self.lock);
+
+        // SAFETY: the spinlock above guarantees this is the only call
+        // accessing `state` for as long as it stays locked.
+        f(unsafe { &mut *self.state.get() })
+    }
+
+    /// Panics because the value was already taken.
+    ///
+    /// In debug builds, the message names the call site of the most recent
+    /// successful `take` on this wrapper, if there was one. Emptying it by
+    /// other means ([`take_opt`](Self::take_opt), [`drop_by`](Self::drop_by))
+    /// does not update or clear that record.
+    fn panic_taken(&self) -> ! {
+        #[cfg(debug_assertions)]
+        match self.taken_at {
+            Some(loc) => panic!("Value already taken at {loc}."),
+            None => panic!("Value taken already."),
+        }
+
+        #[cfg(not(debug_assertions))]
+        panic!("Value taken already.")
+    }
+}
+
+impl<T: Deref> One<T> {
+    /// Returns a reference to the inner value's [`Deref`] target.
     ///
     /// # Panics
     ///
-    /// Panics if argument is none.
-    #[track_caller]
-    fn expect<V>(x: Option<V>) -> V {
-        x.expect("Value taken already.")
+    /// Panics if this value taken already.
+    pub fn as_deref(this: &Self) -> &T::Target {
+        this.deref().deref()
+    }
+}
+
+impl<T: DerefMut> One<T> {
+    /// Returns a mutable reference to the inner value's [`Deref`] target.
+    ///
+    /// # Panics
+    ///
+    /// Panics if this value taken already.
+    pub fn as_deref_mut(this: &mut Self) -> &mut T::Target {
+        this.deref_mut().deref_mut()
     }
 }
 
 impl<T> Default for One<T> {
     fn default() -> Self {
-        Self(Default::default())
+        Self::none()
+    }
+}
+
+impl<T> Drop for One<T> {
+    fn drop(&mut self) {
+        Self::drop_by(self);
+    }
+}
+
+// `Copy` cannot be restored here: the `Drop` impl above disqualifies it
+// outright (a type cannot be both `Copy` and `Drop`), regardless of how
+// `state` is represented. That is an intended consequence of
+// [`with_drop`](Self::with_drop), not an oversight.
+
+impl<T: fmt::Debug> fmt::Debug for One<T> {
+    /// Formats this wrapper without forcing a still-uninitialized value, so
+    /// formatting for debugging purposes cannot trigger evaluator side
+    /// effects.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        enum Peek<T> {
+            Ready(*const T),
+            Uninit,
+            Taken,
+        }
+
+        // Only the discriminant and a raw pointer are captured while
+        // locked; `T`'s own `Debug` impl (arbitrary caller code) runs
+        // below, after the lock has been released, so it can never hold
+        // up every other access to this `One` — including on panic/unwind.
+        let peek = self.with_locked_state(|state| match state {
+            State::Ready(value) => Peek::Ready(value as *const T),
+            State::Uninit(_) => Peek::Uninit,
+            State::Taken => Peek::Taken,
+        });
+
+        match peek {
+            // SAFETY: see the identical pattern in `get`.
+            Peek::Ready(ptr) => write!(f, "One({:?})", unsafe { &*ptr }),
+            Peek::Uninit => f.write_str("One(<uninit>)"),
+            Peek::Taken => f.write_str("One(<taken>)"),
+        }
+    }
+}
+
+impl<T: Clone> Clone for One<T> {
+    /// Clones the value, forcing its construction first if still uninitialized.
+    ///
+    /// The clone is a fresh, plain [`One`]: it never carries an on-drop
+    /// closure or a recorded take call site, even if `self` does, since a
+    /// boxed closure cannot generally be duplicated.
+    ///
+    /// # Panics
+    ///
+    /// Panics if this value taken already.
+    fn clone(&self) -> Self {
+        match Self::get(self) {
+            Some(value) => Self::new(value.clone()),
+            None => self.panic_taken(),
+        }
+    }
+}
+
+impl<T: PartialEq> PartialEq for One<T> {
+    /// Compares the values, forcing construction of either side first if
+    /// still uninitialized. Two wrappers whose values were both taken are
+    /// equal, just as `None == None` is for [`Option`].
+    fn eq(&self, other: &Self) -> bool {
+        Self::get(self) == Self::get(other)
+    }
+}
+
+impl<T: Eq> Eq for One<T> {}
+
+impl<T: PartialOrd> PartialOrd for One<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Self::get(self).partial_cmp(&Self::get(other))
+    }
+}
+
+impl<T: Ord> Ord for One<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        Self::get(self).cmp(&Self::get(other))
+    }
+}
+
+impl<T: Hash> Hash for One<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        Self::get(self).hash(state);
     }
 }
 
@@ -110,22 +589,37 @@ impl<T> Deref for One<T> {
 
     /// Dereferences the value.
     ///
+    /// If the value is still uninitialized, this forces its construction first.
+    ///
     /// # Panics
     ///
     /// Panics if this value taken already.
     fn deref(&self) -> &Self::Target {
-        Self::expect(self.0.as_ref())
+        self.force();
+        let ptr = self.with_locked_state(|state| state.as_ready().map(|value| value as *const T));
+        match ptr {
+            // SAFETY: see the identical pattern in `get`.
+            Some(ptr) => unsafe { &*ptr },
+            None => self.panic_taken(),
+        }
     }
 }
 
 impl<T> DerefMut for One<T> {
     /// Mutably dereferences the value.
     ///
+    /// If the value is still uninitialized, this forces its construction first.
+    ///
     /// # Panics
     ///
     /// Panics if this value taken already.
     fn deref_mut(&mut self) -> &mut Self::Target {
-        Self::expect(self.0.as_mut())
+        self.force();
+        if self.state.get_mut().as_ready().is_none() {
+            self.panic_taken();
+        }
+
+        self.state.get_mut().as_ready_mut().expect("checked above")
     }
 }
 
@@ -134,3 +628,261 @@ impl<T> From<T> for One<T> {
         Self::new(value)
     }
 }
+
+impl<T> AsRef<T> for One<T> {
+    /// # Panics
+    ///
+    /// Panics if this value taken already.
+    fn as_ref(&self) -> &T {
+        self
+    }
+}
+
+impl<T> AsMut<T> for One<T> {
+    /// # Panics
+    ///
+    /// Panics if this value taken already.
+    fn as_mut(&mut self) -> &mut T {
+        self
+    }
+}
+
+impl<T> Borrow<T> for One<T> {
+    /// # Panics
+    ///
+    /// Panics if this value taken already.
+    fn borrow(&self) -> &T {
+        self
+    }
+}
+
+impl<T> BorrowMut<T> for One<T> {
+    /// # Panics
+    ///
+    /// Panics if this value taken already.
+    fn borrow_mut(&mut self) -> &mut T {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::cell::Cell;
+    use core::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+    #[test]
+    fn lazy_value_evaluates_once() {
+        static RUNS: AtomicUsize = AtomicUsize::new(0);
+        let mut one = One::new_lazy(|| {
+            RUNS.fetch_add(1, Ordering::SeqCst);
+            42
+        });
+
+        assert_eq!(*one, 42);
+        assert_eq!(*one, 42);
+        assert_eq!(One::get(&one), Some(&42));
+        assert_eq!(RUNS.load(Ordering::SeqCst), 1);
+
+        assert_eq!(One::take(&mut one), 42);
+        assert_eq!(RUNS.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "Evaluator already consumed.")]
+    fn reentrant_force_panics_instead_of_rerunning_evaluator() {
+        thread_local! {
+            static SELF_PTR: Cell<*const One<i32>> = const { Cell::new(core::ptr::null()) };
+        }
+
+        let one = One::new_lazy(|| {
+            let ptr = SELF_PTR.with(Cell::get);
+            // SAFETY: `SELF_PTR` is set to `&one` below before this evaluator
+            // can possibly run.
+            unsafe { &*ptr }.force();
+            42
+        });
+
+        SELF_PTR.with(|cell| cell.set(&one));
+        one.force();
+    }
+
+    #[test]
+    fn dropping_an_unevaluated_lazy_value_never_runs_the_evaluator() {
+        static RAN: AtomicBool = AtomicBool::new(false);
+        drop(One::new_lazy(|| {
+            RAN.store(true, Ordering::SeqCst);
+            42
+        }));
+
+        assert!(!RAN.load(Ordering::SeqCst));
+    }
+
+    #[test]
+    fn with_drop_fires_with_the_value_when_dropped_unused() {
+        static RECEIVED: AtomicUsize = AtomicUsize::new(0);
+        drop(One::with_drop(7, |value| {
+            RECEIVED.store(value, Ordering::SeqCst);
+        }));
+
+        assert_eq!(RECEIVED.load(Ordering::SeqCst), 7);
+    }
+
+    #[test]
+    fn with_drop_is_skipped_once_the_value_was_taken() {
+        static FIRED: AtomicBool = AtomicBool::new(false);
+        let mut one = One::with_drop(7, |_| FIRED.store(true, Ordering::SeqCst));
+
+        assert_eq!(One::take(&mut one), 7);
+        drop(one);
+
+        assert!(!FIRED.load(Ordering::SeqCst));
+    }
+
+    #[test]
+    fn drop_by_fires_on_drop_closure_early_and_leaves_wrapper_reusable() {
+        static RECEIVED: AtomicUsize = AtomicUsize::new(0);
+        let mut one = One::with_drop(7, |value| {
+            RECEIVED.store(value, Ordering::SeqCst);
+        });
+
+        One::drop_by(&mut one);
+        assert_eq!(RECEIVED.load(Ordering::SeqCst), 7);
+        assert!(!One::exists(&one));
+
+        // The wrapper is reusable, and no longer armed with the closure.
+        One::insert(&mut one, 9);
+        drop(one);
+        assert_eq!(RECEIVED.load(Ordering::SeqCst), 7);
+    }
+
+    #[test]
+    fn into_inner_extracts_the_value_and_disarms_on_drop() {
+        static FIRED: AtomicBool = AtomicBool::new(false);
+        let one = One::with_drop(7, |_| FIRED.store(true, Ordering::SeqCst));
+
+        assert_eq!(One::into_inner(one), Some(7));
+        assert!(!FIRED.load(Ordering::SeqCst));
+    }
+
+    #[test]
+    fn get_and_get_mut_never_panic_on_a_taken_value() {
+        let mut one = One::new(5);
+        assert_eq!(One::take(&mut one), 5);
+
+        assert_eq!(One::get(&one), None);
+        assert_eq!(One::get_mut(&mut one), None);
+    }
+
+    #[test]
+    fn get_mut_allows_in_place_mutation() {
+        let mut one = One::new(5);
+        *One::get_mut(&mut one).unwrap() += 1;
+        assert_eq!(*one, 6);
+    }
+
+    #[test]
+    fn take_opt_never_panics_and_disarms_on_drop() {
+        static FIRED: AtomicBool = AtomicBool::new(false);
+        let mut one = One::with_drop(5, |_| FIRED.store(true, Ordering::SeqCst));
+
+        assert_eq!(One::take_opt(&mut one), Some(5));
+        assert_eq!(One::take_opt(&mut one), None);
+        drop(one);
+
+        assert!(!FIRED.load(Ordering::SeqCst));
+    }
+
+    #[test]
+    fn replace_returns_the_previous_ready_value_but_not_an_unevaluated_lazy_one() {
+        let mut one = One::new(5);
+        assert_eq!(One::replace(&mut one, 6), Some(5));
+        assert_eq!(*one, 6);
+
+        static RUNS: AtomicUsize = AtomicUsize::new(0);
+        let mut lazy = One::new_lazy(|| {
+            RUNS.fetch_add(1, Ordering::SeqCst);
+            5
+        });
+        assert_eq!(One::replace(&mut lazy, 6), None);
+        assert_eq!(*lazy, 6);
+        assert_eq!(RUNS.load(Ordering::SeqCst), 0);
+    }
+
+    #[test]
+    fn get_or_insert_with_uses_its_own_closure_over_a_stale_lazy_evaluator() {
+        let mut one = One::new_lazy(|| 111);
+        assert_eq!(*One::get_or_insert_with(&mut one, || 222), 222);
+        assert_eq!(*one, 222);
+    }
+
+    #[test]
+    fn get_or_insert_with_keeps_an_existing_ready_value() {
+        let mut one = One::new(5);
+        assert_eq!(*One::get_or_insert_with(&mut one, || 222), 5);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    fn panic_after_take_names_the_take_call_site() {
+        let mut one = One::new(5);
+        One::take(&mut one); // <- this call site should be named below
+        let line = line!() - 1;
+
+        let message = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            let _ = *one;
+        }))
+        .unwrap_err();
+        let message = message.downcast_ref::<String>().unwrap();
+
+        assert!(message.starts_with("Value already taken at "), "{message}");
+        assert!(message.contains("one.rs"), "{message}");
+        assert!(message.contains(&line.to_string()), "{message}");
+    }
+
+    #[test]
+    #[cfg(not(debug_assertions))]
+    #[should_panic(expected = "Value taken already.")]
+    fn panic_after_take_has_no_call_site_in_release_builds() {
+        let mut one = One::new(5);
+        One::take(&mut one);
+        let _ = *one;
+    }
+
+    #[test]
+    fn concurrent_force_and_get_race_safely_across_threads() {
+        static RUNS: AtomicUsize = AtomicUsize::new(0);
+        let one = std::sync::Arc::new(One::new_lazy(|| {
+            RUNS.fetch_add(1, Ordering::SeqCst);
+            42
+        }));
+
+        let handles: Vec<_> = (0..8)
+            .map(|_| {
+                let one = std::sync::Arc::clone(&one);
+                std::thread::spawn(move || {
+                    // Whichever thread loses the race to force a still-uninitialized
+                    // value panics by design (see the comments inside `force`); that
+                    // is expected here and not what this test is exercising. `get`
+                    // forces too, so it is retried until the winner stores the value.
+                    let _ = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| one.force()));
+                    loop {
+                        let got = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                            One::get(&one).copied()
+                        }));
+                        if let Ok(value) = got {
+                            break value.unwrap();
+                        }
+                    }
+                })
+            })
+            .collect();
+
+        for handle in handles {
+            assert_eq!(handle.join().unwrap(), 42);
+        }
+
+        // However many threads raced to force it, the evaluator ran exactly once.
+        assert_eq!(RUNS.load(Ordering::SeqCst), 1);
+    }
+}